@@ -0,0 +1,118 @@
+//! Derive macros for `bytecord`'s [`FromBytes`] and [`AsBytes`] marker
+//! traits.
+//!
+//! [`FromBytes`]: https://docs.rs/bytecord/latest/bytecord/trait.FromBytes.html
+//! [`AsBytes`]: https://docs.rs/bytecord/latest/bytecord/trait.AsBytes.html
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
+
+/// Checks that the struct is `#[repr(C)]` or `#[repr(packed)]`, returning
+/// the fields on success or a compile error `TokenStream` on failure.
+fn require_fixed_layout<'a>(
+    input: &'a DeriveInput,
+    trait_name: &str,
+) -> Result<&'a Fields, TokenStream> {
+    let has_fixed_repr = input.attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+            && attr
+                .parse_args::<syn::Ident>()
+                .map(|ident| ident == "C" || ident == "packed")
+                .unwrap_or(false)
+    });
+    if !has_fixed_repr {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("deriving `{trait_name}` requires `#[repr(C)]` or `#[repr(packed)]`"),
+        )
+        .to_compile_error()
+        .into());
+    }
+
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            format!("`{trait_name}` can only be derived for structs"),
+        )
+        .to_compile_error()
+        .into()),
+    }
+}
+
+/// Generates a const assertion that `Self` has no inter-field padding,
+/// i.e. that `size_of::<Self>()` equals the sum of its fields' sizes.
+/// `#[repr(C)]`/`#[repr(packed)]` alone only fixes the field order and
+/// doesn't rule out padding, so this is checked separately: without it, a
+/// struct like `#[repr(C)] struct Padded { a: u8, b: u32 }` would derive
+/// `FromBytes`/`AsBytes` cleanly while exposing 3 uninitialized padding
+/// bytes as part of a safe `&[u8]`.
+fn no_padding_assertion(ident: &syn::Ident, ty_generics: &syn::TypeGenerics, field_types: &[&Type]) -> TokenStream2 {
+    quote! {
+        const _: () = {
+            let field_sizes_sum = 0usize #(+ ::core::mem::size_of::<#field_types>())*;
+            ::core::assert!(
+                field_sizes_sum == ::core::mem::size_of::<#ident #ty_generics>(),
+                "struct has padding between fields, which is not allowed by this trait",
+            );
+        };
+    }
+}
+
+/// Derives [`FromBytes`](bytecord::FromBytes) for a `#[repr(C)]` or
+/// `#[repr(packed)]` struct whose fields are all `FromBytes`.
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match require_fixed_layout(&input, "FromBytes") {
+        Ok(fields) => fields,
+        Err(err) => return err,
+    };
+
+    let ident = &input.ident;
+    let field_types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let no_padding_assertion = no_padding_assertion(ident, &ty_generics, &field_types);
+
+    quote! {
+        const _: fn() = || {
+            fn assert_from_bytes<T: ::bytecord::FromBytes>() {}
+            #(assert_from_bytes::<#field_types>();)*
+        };
+
+        #no_padding_assertion
+
+        unsafe impl #impl_generics ::bytecord::FromBytes for #ident #ty_generics #where_clause {}
+    }
+    .into()
+}
+
+/// Derives [`AsBytes`](bytecord::AsBytes) for a `#[repr(C)]` or
+/// `#[repr(packed)]` struct whose fields are all `AsBytes`.
+#[proc_macro_derive(AsBytes)]
+pub fn derive_as_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match require_fixed_layout(&input, "AsBytes") {
+        Ok(fields) => fields,
+        Err(err) => return err,
+    };
+
+    let ident = &input.ident;
+    let field_types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let no_padding_assertion = no_padding_assertion(ident, &ty_generics, &field_types);
+
+    quote! {
+        const _: fn() = || {
+            fn assert_as_bytes<T: ::bytecord::AsBytes>() {}
+            #(assert_as_bytes::<#field_types>();)*
+        };
+
+        #no_padding_assertion
+
+        unsafe impl #impl_generics ::bytecord::AsBytes for #ident #ty_generics #where_clause {}
+    }
+    .into()
+}
@@ -1,5 +1,7 @@
 //! This module provies [`ByteCordBuilder`].
 
+use crate::AsBytes;
+
 /// ByteCordBuilder
 pub struct ByteCordBuilder {
     inner: Vec<u8>,
@@ -42,6 +44,13 @@ impl ByteCordBuilder {
         self.append_from_slice(&bytes[..]);
     }
 
+    /// Appends the in-memory representation of a `T: AsBytes` value to this
+    /// builder.
+    #[inline]
+    pub fn append_value<T: AsBytes>(&mut self, value: &T) {
+        self.append_from_slice(value.as_bytes());
+    }
+
     /// Coverts this builder into [`Box<[u8]>`].
     #[inline]
     pub fn into_boxed_slice(self) -> Box<[u8]> {
@@ -49,6 +58,26 @@ impl ByteCordBuilder {
     }
 }
 
+/// Writes `buf` verbatim with no alignment padding, unlike
+/// [`append_from_slice`](ByteCordBuilder::append_from_slice). A real
+/// `Write` consumer (e.g. a buffered writer) may split one logical write
+/// across several `write` calls, and padding after each individual call
+/// would insert spurious zero bytes in between; call
+/// [`append_from_slice`](ByteCordBuilder::append_from_slice) directly if
+/// alignment padding between writes is what you want. `flush` is a no-op
+/// since this builder has no underlying sink to flush.
+#[cfg(feature = "std")]
+impl std::io::Write for ByteCordBuilder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.inner.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 impl ByteCordBuilder {
     #[inline]
     #[allow(missing_docs)]
@@ -158,3 +187,47 @@ impl ByteCordBuilder {
         self.append(&value.to_le_bytes());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_from_slice_pads_to_alignment() {
+        let mut builder = ByteCordBuilder::new(4);
+        builder.append_from_slice(&[1, 2, 3]);
+        assert_eq!(&*builder.into_boxed_slice(), &[1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn append_value_uses_as_bytes_representation() {
+        let mut builder = ByteCordBuilder::new(1);
+        builder.append_value(&0x0102_0304u32.to_be());
+        assert_eq!(&*builder.into_boxed_slice(), &0x0102_0304u32.to_be().to_ne_bytes());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_is_byte_exact_across_split_calls() {
+        use std::io::Write;
+
+        // alignment 4 would pad `append_from_slice` between calls; `write`
+        // must not insert that padding even when a caller splits one
+        // logical write into several `write` calls.
+        let mut builder = ByteCordBuilder::new(4);
+        builder.write_all(&[1, 2, 3]).unwrap();
+        builder.write_all(&[4, 5]).unwrap();
+        assert_eq!(&*builder.into_boxed_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn flush_is_a_no_op() {
+        use std::io::Write;
+
+        let mut builder = ByteCordBuilder::new(1);
+        builder.write_all(&[1]).unwrap();
+        builder.flush().unwrap();
+        assert_eq!(&*builder.into_boxed_slice(), &[1]);
+    }
+}
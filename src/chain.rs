@@ -0,0 +1,189 @@
+//! This module provides [`Chain`].
+
+use std::borrow::Cow;
+
+/// Logically concatenates an ordered list of buffers without copying any
+/// of them, tracking each segment's cumulative offset so that a byte
+/// position can be located with a binary search rather than a linear
+/// scan.
+///
+/// # Examples
+/// ```
+/// # use bytecord::ByteCord;
+///
+/// let a = vec![1u8, 2, 3];
+/// let b = vec![4u8, 5, 6];
+/// let cord = ByteCord::chain(a, b);
+///
+/// assert_eq!(cord.len(), 6);
+/// ```
+pub struct Chain<S> {
+    segments: Vec<S>,
+    /// Cumulative offsets, one more than `segments`: `offsets[0] == 0`,
+    /// and segment `i` covers the byte range `offsets[i]..offsets[i + 1]`.
+    offsets: Vec<usize>,
+}
+
+impl<S: AsRef<[u8]>> Chain<S> {
+    /// Returns a new [`Chain`] logically concatenating `a` followed by
+    /// `b`. Use [`push`](Self::push) to append further segments.
+    #[inline]
+    pub fn new(a: S, b: S) -> Self {
+        let mut chain = Chain {
+            segments: Vec::with_capacity(2),
+            offsets: vec![0],
+        };
+        chain.push(a);
+        chain.push(b);
+        chain
+    }
+
+    /// Appends a segment to the end of this chain.
+    #[inline]
+    pub fn push(&mut self, segment: S) {
+        let end = self.len() + segment.as_ref().len();
+        self.segments.push(segment);
+        self.offsets.push(end);
+    }
+
+    /// Returns the segments making up this chain, in order.
+    #[inline]
+    pub fn segments(&self) -> &[S] {
+        &self.segments
+    }
+
+    /// Returns length of this chain.
+    #[inline]
+    pub fn len(&self) -> usize {
+        *self.offsets.last().unwrap_or(&0)
+    }
+
+    /// Returns `true` if all segments are empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the index of the segment covering byte `position`.
+    ///
+    /// Binary searches the offset table rather than scanning segments
+    /// linearly. `position` must be less than `self.len()`.
+    #[inline]
+    fn segment_at(&self, position: usize) -> usize {
+        self.offsets.partition_point(|&offset| offset <= position) - 1
+    }
+
+    /// Returns a byte slice or owned fallback starting at `position` with
+    /// the given `length`, or `None` if out of bounds.
+    ///
+    /// When the requested range lies entirely inside one segment the
+    /// result borrows from it directly; when it straddles segment
+    /// boundaries the bytes are copied into an owned buffer, since a
+    /// single `&[u8]` cannot span multiple allocations.
+    pub fn at_n(&self, position: usize, length: usize) -> Option<Cow<'_, [u8]>> {
+        let end = position.checked_add(length)?;
+        if end > self.len() {
+            return None;
+        }
+        if length == 0 {
+            return Some(Cow::Borrowed(&[]));
+        }
+
+        let start_idx = self.segment_at(position);
+        let end_idx = self.segment_at(end - 1);
+
+        if start_idx == end_idx {
+            let offset = self.offsets[start_idx];
+            Some(Cow::Borrowed(
+                &self.segments[start_idx].as_ref()[position - offset..end - offset],
+            ))
+        } else {
+            let mut owned = Vec::with_capacity(length);
+            for idx in start_idx..=end_idx {
+                let segment = self.segments[idx].as_ref();
+                let offset = self.offsets[idx];
+                let next_offset = self.offsets[idx + 1];
+                let lo = position.max(offset) - offset;
+                let hi = end.min(next_offset) - offset;
+                owned.extend_from_slice(&segment[lo..hi]);
+            }
+            Some(Cow::Owned(owned))
+        }
+    }
+
+    /// Returns an array of size `N` starting at `position`, or `None` if
+    /// out of bounds.
+    ///
+    /// Borrows directly from the underlying segment when the window lies
+    /// inside one of them; only copies when it straddles a segment
+    /// boundary.
+    pub fn at<const N: usize>(&self, position: usize) -> Option<Cow<'_, [u8; N]>> {
+        self.at_n(position, N).map(|bytes| match bytes {
+            Cow::Borrowed(slice) => Cow::Borrowed(unsafe { &*(slice.as_ptr() as *const [u8; N]) }),
+            Cow::Owned(vec) => {
+                let mut array = [0u8; N];
+                array.copy_from_slice(&vec);
+                Cow::Owned(array)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_and_is_empty_reflect_pushed_segments() {
+        let mut chain = Chain::new(vec![1u8, 2], vec![3u8, 4, 5]);
+        assert_eq!(chain.len(), 5);
+        assert!(!chain.is_empty());
+
+        chain.push(vec![6u8]);
+        assert_eq!(chain.len(), 6);
+        assert_eq!(chain.segments().len(), 3);
+    }
+
+    #[test]
+    fn is_empty_when_all_segments_are_empty() {
+        let chain: Chain<Vec<u8>> = Chain::new(vec![], vec![]);
+        assert!(chain.is_empty());
+    }
+
+    #[test]
+    fn at_n_borrows_when_the_range_lies_within_one_segment() {
+        let chain = Chain::new(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        assert!(matches!(chain.at_n(1, 2), Some(Cow::Borrowed(_))));
+        assert_eq!(chain.at_n(1, 2).unwrap(), Cow::Borrowed(&[2u8, 3][..]));
+    }
+
+    #[test]
+    fn at_n_copies_when_the_range_straddles_a_segment_boundary() {
+        let chain = Chain::new(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        let result = chain.at_n(2, 3).unwrap();
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result.into_owned(), vec![3u8, 4, 5]);
+    }
+
+    #[test]
+    fn at_n_straddles_across_more_than_two_segments() {
+        let mut chain = Chain::new(vec![1u8], vec![2u8]);
+        chain.push(vec![3u8]);
+        chain.push(vec![4u8]);
+        assert_eq!(chain.at_n(0, 4).unwrap().into_owned(), vec![1u8, 2, 3, 4]);
+    }
+
+    #[test]
+    fn at_n_rejects_out_of_bounds_ranges() {
+        let chain = Chain::new(vec![1u8, 2], vec![3u8, 4]);
+        assert_eq!(chain.at_n(3, 2), None);
+        assert_eq!(chain.at_n(usize::MAX, 1), None);
+    }
+
+    #[test]
+    fn at_array_borrows_or_copies_matching_at_n() {
+        let chain = Chain::new(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        assert_eq!(chain.at::<2>(0).unwrap().into_owned(), [1u8, 2]);
+        assert_eq!(chain.at::<2>(2).unwrap().into_owned(), [3u8, 4]);
+    }
+}
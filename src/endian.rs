@@ -0,0 +1,228 @@
+//! This module provides byte-order-aware integer types ([`U16`], [`U32`],
+//! ...) that can be embedded directly as fields of a [`FromBytes`]/
+//! [`AsBytes`] struct and read through [`ByteCordReader::next_ref`].
+//!
+//! [`ByteCordReader::next_ref`]: crate::ByteCordReader::next_ref
+
+use std::marker::PhantomData;
+
+use crate::{AsBytes, FromBytes};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A byte order marker used by the integer types in this module.
+///
+/// This trait is sealed; [`Be`] and [`Le`] are the only implementors.
+pub trait Endian: sealed::Sealed {}
+
+/// Big-endian (network) byte order.
+pub struct Be;
+
+/// Little-endian byte order.
+pub struct Le;
+
+impl sealed::Sealed for Be {}
+impl sealed::Sealed for Le {}
+impl Endian for Be {}
+impl Endian for Le {}
+
+macro_rules! define_endian_int {
+    ($name:ident, $native:ty, $size:literal, $doc:literal) => {
+        #[doc = $doc]
+        ///
+        /// Has alignment 1 and no padding, so it can be embedded as a
+        /// field of a `#[repr(C)]` struct deriving [`FromBytes`]/
+        /// [`AsBytes`] and read in place via
+        #[doc = concat!("[`ByteCordReader::next_ref::<", stringify!($name), "<E>>`](crate::ByteCordReader::next_ref).")]
+        #[repr(transparent)]
+        pub struct $name<E: Endian> {
+            bytes: [u8; $size],
+            _endian: PhantomData<E>,
+        }
+
+        // Implemented manually rather than derived: a derived impl would
+        // add a spurious `E: Clone`/`E: Debug`/etc. bound from the
+        // `PhantomData<E>` field, even though `E` (`Be`/`Le`) never needs
+        // to implement those traits for `$name<E>` to.
+        impl<E: Endian> Clone for $name<E> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<E: Endian> Copy for $name<E> {}
+
+        impl<E: Endian> std::fmt::Debug for $name<E> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name)).field("bytes", &self.bytes).finish()
+            }
+        }
+
+        impl<E: Endian> PartialEq for $name<E> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.bytes == other.bytes
+            }
+        }
+
+        impl<E: Endian> Eq for $name<E> {}
+
+        impl<E: Endian> std::hash::Hash for $name<E> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.bytes.hash(state);
+            }
+        }
+
+        impl<E: Endian> $name<E> {
+            #[inline]
+            fn from_bytes(bytes: [u8; $size]) -> Self {
+                Self {
+                    bytes,
+                    _endian: PhantomData,
+                }
+            }
+        }
+
+        impl $name<Be> {
+            /// Returns the native-endian value.
+            #[inline]
+            pub fn get(&self) -> $native {
+                <$native>::from_be_bytes(self.bytes)
+            }
+
+            /// Sets the value, encoding it in this type's byte order.
+            #[inline]
+            pub fn set(&mut self, value: $native) {
+                self.bytes = value.to_be_bytes();
+            }
+
+            /// Returns a new value encoding `value` in this type's byte
+            /// order.
+            #[inline]
+            pub fn new(value: $native) -> Self {
+                Self::from_bytes(value.to_be_bytes())
+            }
+        }
+
+        impl $name<Le> {
+            /// Returns the native-endian value.
+            #[inline]
+            pub fn get(&self) -> $native {
+                <$native>::from_le_bytes(self.bytes)
+            }
+
+            /// Sets the value, encoding it in this type's byte order.
+            #[inline]
+            pub fn set(&mut self, value: $native) {
+                self.bytes = value.to_le_bytes();
+            }
+
+            /// Returns a new value encoding `value` in this type's byte
+            /// order.
+            #[inline]
+            pub fn new(value: $native) -> Self {
+                Self::from_bytes(value.to_le_bytes())
+            }
+        }
+
+        impl From<$name<Be>> for $native {
+            #[inline]
+            fn from(value: $name<Be>) -> Self {
+                value.get()
+            }
+        }
+
+        impl From<$name<Le>> for $native {
+            #[inline]
+            fn from(value: $name<Le>) -> Self {
+                value.get()
+            }
+        }
+
+        impl From<$native> for $name<Be> {
+            #[inline]
+            fn from(value: $native) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$native> for $name<Le> {
+            #[inline]
+            fn from(value: $native) -> Self {
+                Self::new(value)
+            }
+        }
+
+        // SAFETY: every bit pattern of `[u8; $size]` is a valid instance,
+        // and the type has no padding since it is `repr(transparent)` over
+        // a byte array.
+        unsafe impl<E: Endian> FromBytes for $name<E> {}
+        unsafe impl<E: Endian> AsBytes for $name<E> {}
+    };
+}
+
+define_endian_int!(U16, u16, 2, "A 16-bit unsigned integer in a specific byte order.");
+define_endian_int!(U32, u32, 4, "A 32-bit unsigned integer in a specific byte order.");
+define_endian_int!(U64, u64, 8, "A 64-bit unsigned integer in a specific byte order.");
+define_endian_int!(U128, u128, 16, "A 128-bit unsigned integer in a specific byte order.");
+define_endian_int!(I16, i16, 2, "A 16-bit signed integer in a specific byte order.");
+define_endian_int!(I32, i32, 4, "A 32-bit signed integer in a specific byte order.");
+define_endian_int!(I64, i64, 8, "A 64-bit signed integer in a specific byte order.");
+define_endian_int!(I128, i128, 16, "A 128-bit signed integer in a specific byte order.");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn be_roundtrips_through_get_set_new() {
+        let value = U32::<Be>::new(0x0102_0304);
+        assert_eq!(value.get(), 0x0102_0304);
+        assert_eq!(value.bytes, [0x01, 0x02, 0x03, 0x04]);
+
+        let mut value = U32::<Be>::new(0);
+        value.set(0x0a0b_0c0d);
+        assert_eq!(value.get(), 0x0a0b_0c0d);
+    }
+
+    #[test]
+    fn le_roundtrips_through_get_set_new() {
+        let value = U32::<Le>::new(0x0102_0304);
+        assert_eq!(value.get(), 0x0102_0304);
+        assert_eq!(value.bytes, [0x04, 0x03, 0x02, 0x01]);
+
+        let mut value = U32::<Le>::new(0);
+        value.set(0x0a0b_0c0d);
+        assert_eq!(value.get(), 0x0a0b_0c0d);
+    }
+
+    #[test]
+    fn is_copy_clone_and_equality_compares_by_bytes() {
+        let a = I64::<Be>::new(-1);
+        let b = a;
+        let c = a.clone();
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_ne!(a, I64::<Be>::new(1));
+    }
+
+    #[test]
+    fn is_hashable() {
+        let mut set = HashSet::new();
+        set.insert(U16::<Le>::new(1));
+        set.insert(U16::<Le>::new(2));
+        set.insert(U16::<Le>::new(1));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn debug_includes_type_name_and_bytes() {
+        let value = U16::<Be>::new(0x0102);
+        assert_eq!(format!("{value:?}"), "U16 { bytes: [1, 2] }");
+    }
+}
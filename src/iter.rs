@@ -0,0 +1,213 @@
+//! This module provides iterator adapters over a [`ByteCordReader`]:
+//! [`IterU8`], [`Chunks`], and [`IterValues`].
+
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use crate::{ByteCordReader, Chain, FromBytes};
+
+/// Iterator over successive `u8`s, returned by
+/// [`ByteCordReader::iter_u8`].
+pub struct IterU8<'r, 'a, T> {
+    pub(crate) reader: &'r mut ByteCordReader<'a, T>,
+}
+
+impl<T: AsRef<[u8]>> Iterator for IterU8<'_, '_, T> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.reader.next_u8()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.reader.remaining();
+        (count, Some(count))
+    }
+}
+
+impl<S: AsRef<[u8]>> Iterator for IterU8<'_, '_, Chain<S>> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.reader.next_copy::<u8>()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.reader.remaining();
+        (count, Some(count))
+    }
+}
+
+/// Iterator over successive aligned `&'a [u8]` windows, returned by
+/// [`ByteCordReader::chunks`].
+///
+/// By default the final window is yielded even if shorter than `size`;
+/// call [`exact`](Chunks::exact) to drop it instead.
+pub struct Chunks<'r, 'a, T> {
+    pub(crate) reader: &'r mut ByteCordReader<'a, T>,
+    pub(crate) size: usize,
+    pub(crate) exact: bool,
+}
+
+impl<'r, 'a, T> Chunks<'r, 'a, T> {
+    /// When set, a final window shorter than `size` is dropped instead of
+    /// being yielded as a partial chunk. Defaults to `false`.
+    #[inline]
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+}
+
+impl<'a, T: AsRef<[u8]>> Iterator for Chunks<'_, 'a, T> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let remaining = self.reader.remaining();
+        if remaining == 0 {
+            return None;
+        }
+        if remaining < self.size {
+            return if self.exact {
+                None
+            } else {
+                self.reader.next_n(remaining)
+            };
+        }
+        self.reader.next_n(self.size)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.reader.remaining();
+        let full = remaining / self.size;
+        let count = full + (!self.exact && remaining % self.size != 0) as usize;
+        (count, Some(count))
+    }
+}
+
+impl<'a, S: AsRef<[u8]>> Iterator for Chunks<'_, 'a, Chain<S>> {
+    type Item = Cow<'a, [u8]>;
+
+    fn next(&mut self) -> Option<Cow<'a, [u8]>> {
+        let remaining = self.reader.remaining();
+        if remaining == 0 {
+            return None;
+        }
+        if remaining < self.size {
+            return if self.exact {
+                None
+            } else {
+                self.reader.next_n(remaining)
+            };
+        }
+        self.reader.next_n(self.size)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.reader.remaining();
+        let full = remaining / self.size;
+        let count = full + (!self.exact && remaining % self.size != 0) as usize;
+        (count, Some(count))
+    }
+}
+
+/// Iterator over successive decoded `U: FromBytes` records, returned by
+/// [`ByteCordReader::iter_values`].
+pub struct IterValues<'r, 'a, T, U> {
+    pub(crate) reader: &'r mut ByteCordReader<'a, T>,
+    pub(crate) _marker: PhantomData<U>,
+}
+
+impl<T: AsRef<[u8]>, U: FromBytes + Copy> Iterator for IterValues<'_, '_, T, U> {
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self) -> Option<U> {
+        self.reader.next_copy::<U>()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.reader.remaining() / std::mem::size_of::<U>();
+        (count, Some(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ByteCord;
+
+    #[test]
+    fn iter_u8_yields_every_byte() {
+        let cord = ByteCord::new(vec![1u8, 2, 3]);
+        let mut reader = cord.read();
+        assert_eq!(reader.iter_u8().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn chunks_yields_a_short_final_window_unless_exact() {
+        let cord = ByteCord::new((0..5u8).collect::<Vec<_>>());
+
+        let mut reader = cord.read();
+        assert_eq!(
+            reader.chunks(2).collect::<Vec<_>>(),
+            vec![&[0u8, 1][..], &[2, 3][..], &[4][..]]
+        );
+
+        let mut reader = cord.read();
+        assert_eq!(
+            reader.chunks(2).exact(true).collect::<Vec<_>>(),
+            vec![&[0u8, 1][..], &[2, 3][..]]
+        );
+    }
+
+    #[test]
+    fn chunks_size_hint_matches_actual_count() {
+        let cord = ByteCord::new((0..5u8).collect::<Vec<_>>());
+        let mut reader = cord.read();
+        let chunks = reader.chunks(2);
+        assert_eq!(chunks.size_hint(), (3, Some(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be greater than 0")]
+    fn chunks_panics_on_zero_size() {
+        let cord = ByteCord::new(vec![1u8]);
+        let mut reader = cord.read();
+        reader.chunks(0);
+    }
+
+    #[test]
+    fn iter_values_decodes_successive_records() {
+        let cord = ByteCord::new(vec![1u8, 0, 2, 0]);
+        let mut reader = cord.read();
+        assert_eq!(reader.iter_values::<u16>().collect::<Vec<_>>(), vec![1u16, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "size_of::<U>() must be greater than 0")]
+    fn iter_values_panics_on_zero_sized_type() {
+        let cord = ByteCord::new(vec![1u8]);
+        let mut reader = cord.read();
+        reader.iter_values::<[u8; 0]>();
+    }
+}
+
+impl<S: AsRef<[u8]>, U: FromBytes + Copy> Iterator for IterValues<'_, '_, Chain<S>, U> {
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self) -> Option<U> {
+        self.reader.next_copy::<U>()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.reader.remaining() / std::mem::size_of::<U>();
+        (count, Some(count))
+    }
+}
@@ -40,11 +40,34 @@
 
 #![warn(missing_docs)]
 
+// The derive macros expand to paths rooted at `::bytecord`; this lets
+// in-crate tests use them on local test-only structs the same way an
+// external consumer would.
+#[cfg(test)]
+extern crate self as bytecord;
+
 pub mod builder;
+pub mod chain;
+pub mod endian;
+pub mod iter;
+pub mod marker;
 pub mod reader;
+pub mod take;
+
+use std::borrow::Cow;
 
 pub use builder::ByteCordBuilder;
+pub use chain::Chain;
+pub use endian::{Be, Endian, Le, I128, I16, I32, I64, U128, U16, U32, U64};
+pub use iter::{Chunks, IterU8, IterValues};
+pub use marker::{AsBytes, FromBytes};
 pub use reader::ByteCordReader;
+pub use take::Take;
+
+/// Derives [`FromBytes`] and [`AsBytes`] for `#[repr(C)]`/`#[repr(packed)]`
+/// structs whose fields all implement the respective trait.
+#[cfg(feature = "derive")]
+pub use bytecord_derive::{AsBytes, FromBytes};
 
 /// ByteCord.
 ///
@@ -132,6 +155,59 @@ impl<T: AsRef<[u8]>> ByteCord<T> {
     }
 }
 
+impl<S: AsRef<[u8]>> ByteCord<Chain<S>> {
+    /// Returns a new [`ByteCord`] logically concatenating `a` followed by
+    /// `b` without copying either buffer.
+    ///
+    /// Use [`Chain::push`] (via [`ByteCord::chain_mut`]) to append further
+    /// segments for true N-segment chains.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bytecord::ByteCord;
+    ///
+    /// let cord = ByteCord::chain(vec![1u8, 2], vec![3u8, 4]);
+    /// assert_eq!(cord.len(), 4);
+    /// ```
+    pub fn chain(a: S, b: S) -> Self {
+        ByteCord::new(Chain::new(a, b))
+    }
+
+    /// Returns a mutable reference to the underlying [`Chain`], e.g. to
+    /// append further segments with [`Chain::push`].
+    pub fn chain_mut(&mut self) -> &mut Chain<S> {
+        &mut self.data
+    }
+
+    /// Returns a byte slice or owned fallback starting at `position` with
+    /// the given `length`, or `None` if out of bounds.
+    ///
+    /// Mirrors [`ByteCord::at_n`], but returns a [`Cow<[u8]>`](Cow) since
+    /// the requested range may straddle a segment boundary.
+    pub fn at_n(&self, position: usize, length: usize) -> Option<Cow<'_, [u8]>> {
+        self.data.at_n(position, length)
+    }
+
+    /// Returns an array of size `N` starting at `position`, or `None` if
+    /// out of bounds.
+    ///
+    /// Mirrors [`ByteCord::at`], but returns a [`Cow<[u8; N]>`](Cow) since
+    /// the requested range may straddle a segment boundary.
+    pub fn at<const N: usize>(&self, position: usize) -> Option<Cow<'_, [u8; N]>> {
+        self.data.at(position)
+    }
+
+    /// Returns length of this cord.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the underlying data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
 impl<T: AsMut<[u8]>> ByteCord<T> {
     /// Returns a mutable byte slice starting at position with given length
     /// or None if out of bounds.
@@ -0,0 +1,89 @@
+//! This module provides the [`FromBytes`] and [`AsBytes`] marker traits.
+
+/// A type for which any byte pattern of `size_of::<Self>()` bytes is a
+/// valid instance.
+///
+/// This allows [`ByteCordReader::next_ref`](crate::ByteCordReader::next_ref)
+/// to reinterpret a byte slice as `&Self` without constructing or
+/// validating the value.
+///
+/// # Safety
+///
+/// Implementors must guarantee that every bit pattern of length
+/// `size_of::<Self>()` is a valid instance of `Self`. This rules out types
+/// with padding, niches, or invalid bit patterns (e.g. `bool`, `char`,
+/// references, or most enums). The derive macro checks this mechanically
+/// for `#[repr(C)]`/`#[repr(packed)]` structs whose fields are all
+/// `FromBytes`.
+pub unsafe trait FromBytes {}
+
+/// A type with no padding bytes that may be viewed as raw bytes.
+///
+/// This allows [`ByteCordBuilder::append_value`](crate::ByteCordBuilder::append_value)
+/// to append a value's in-memory representation directly.
+///
+/// # Safety
+///
+/// Implementors must guarantee that every byte of `Self`'s representation
+/// is initialized, i.e. the type has no padding. The derive macro checks
+/// this mechanically for `#[repr(C)]`/`#[repr(packed)]` structs whose
+/// fields are all `AsBytes`.
+pub unsafe trait AsBytes: Sized {
+    /// Returns the value's in-memory representation as a byte slice.
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, std::mem::size_of::<Self>()) }
+    }
+}
+
+macro_rules! impl_markers_for_primitives {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl FromBytes for $t {}
+            unsafe impl AsBytes for $t {}
+        )*
+    };
+}
+
+impl_markers_for_primitives!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+unsafe impl<T: FromBytes, const N: usize> FromBytes for [T; N] {}
+unsafe impl<T: AsBytes, const N: usize> AsBytes for [T; N] {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_bytes_primitive_matches_native_endian_representation() {
+        let value: u32 = 0x0102_0304;
+        assert_eq!(value.as_bytes(), &value.to_ne_bytes());
+    }
+
+    #[test]
+    fn as_bytes_array_concatenates_element_bytes() {
+        let value: [u16; 2] = [1, 2];
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&value[0].to_ne_bytes());
+        expected.extend_from_slice(&value[1].to_ne_bytes());
+        assert_eq!(value.as_bytes(), &expected[..]);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_roundtrips_a_non_padded_repr_c_struct() {
+        #[repr(C)]
+        #[derive(bytecord::FromBytes, bytecord::AsBytes)]
+        struct Header {
+            a: u8,
+            b: u8,
+            c: u16,
+        }
+
+        let header = Header { a: 1, b: 2, c: 3 };
+        assert_eq!(header.as_bytes().len(), std::mem::size_of::<Header>());
+        assert_eq!(std::mem::size_of::<Header>(), 4);
+    }
+}
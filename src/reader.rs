@@ -1,6 +1,10 @@
 //! This module provies [`ByteCordReader`].
 
-use crate::ByteCord;
+use std::borrow::Cow;
+
+use std::marker::PhantomData;
+
+use crate::{ByteCord, Chain, Chunks, FromBytes, IterU8, IterValues, Take};
 
 /// Reader of an aligned [`ByteCord`].
 ///
@@ -50,6 +54,12 @@ impl<'a, T> ByteCordReader<'a, T> {
     pub fn new(cord: &'a ByteCord<T>) -> Self {
         Self::with_alignment(cord, 1)
     }
+
+    /// Returns the current byte position.
+    #[inline]
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
 }
 
 impl<'a, T: AsRef<[u8]>> ByteCordReader<'a, T> {
@@ -93,6 +103,293 @@ impl<'a, T: AsRef<[u8]>> ByteCordReader<'a, T> {
     pub fn remaining(&self) -> usize {
         self.cord.len().saturating_sub(self.position)
     }
+
+    /// Returns a reference to a `U: FromBytes` value at the current
+    /// position and advances the position, or `None` if out of bounds or if
+    /// the underlying bytes are not aligned to `align_of::<U>()`.
+    ///
+    /// Unlike [`next`](Self::next), this reinterprets the underlying bytes
+    /// in place instead of copying them; see
+    /// [`next_copy`](Self::next_copy) for a copying fallback that works
+    /// regardless of alignment.
+    #[inline]
+    pub fn next_ref<U: FromBytes>(&mut self) -> Option<&'a U> {
+        // The reader's logical `position` being a multiple of
+        // `align_of::<U>()` says nothing about the real alignment of the
+        // underlying buffer's address, which may be arbitrarily offset
+        // from the start of the allocation that backs it; the real
+        // address is what must be checked before reinterpreting it as
+        // `&U`.
+        let slice = self.peek_n(std::mem::size_of::<U>())?;
+        if !(slice.as_ptr() as usize).is_multiple_of(std::mem::align_of::<U>()) {
+            return None;
+        }
+        self.next_n(std::mem::size_of::<U>())
+            .map(|slice| unsafe { &*(slice.as_ptr() as *const U) })
+    }
+
+    /// Returns a copy of a `U: FromBytes` value at the current position and
+    /// advances the position, or `None` if out of bounds.
+    ///
+    /// Unlike [`next_ref`](Self::next_ref), this works regardless of the
+    /// current position's alignment, at the cost of copying the value's
+    /// bytes.
+    #[inline]
+    pub fn next_copy<U: FromBytes + Copy>(&mut self) -> Option<U> {
+        self.next_n(std::mem::size_of::<U>())
+            .map(|slice| unsafe { std::ptr::read_unaligned(slice.as_ptr() as *const U) })
+    }
+
+    /// Returns `length` bytes at the current position without advancing,
+    /// or `None` if out of bounds.
+    #[inline]
+    pub fn peek_n(&self, length: usize) -> Option<&'a [u8]> {
+        self.cord.at_n(self.position, length)
+    }
+
+    /// Returns an array of size S at the current position without
+    /// advancing, or `None` if out of bounds.
+    #[inline]
+    pub fn peek<const S: usize>(&self) -> Option<&'a [u8; S]> {
+        self.cord.at::<S>(self.position)
+    }
+
+    /// Returns a bounded sub-reader over the next `n` bytes that refuses
+    /// reads past that limit, recovering this reader's position advances
+    /// via [`Take::into_inner`].
+    ///
+    /// Useful for length-prefixed framing: read a length, then `take` a
+    /// bounded region for the payload parser so it cannot over-read into
+    /// the next frame.
+    #[inline]
+    pub fn take(&mut self, n: usize) -> Take<'_, 'a, T> {
+        Take::new(self, n)
+    }
+
+    /// Returns an iterator over successive `u8`s until this reader is
+    /// exhausted.
+    #[inline]
+    pub fn iter_u8(&mut self) -> IterU8<'_, 'a, T> {
+        IterU8 { reader: self }
+    }
+
+    /// Returns an iterator over successive aligned `&'a [u8]` windows of
+    /// length `size`.
+    ///
+    /// The final window is yielded even if shorter than `size`; call
+    /// [`Chunks::exact`] on the result to drop it instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn chunks(&mut self, size: usize) -> Chunks<'_, 'a, T> {
+        assert!(size > 0, "chunk size must be greater than 0");
+        Chunks {
+            reader: self,
+            size,
+            exact: false,
+        }
+    }
+
+    /// Returns an iterator over successive decoded `U: FromBytes` records
+    /// until fewer than `size_of::<U>()` bytes remain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size_of::<U>()` is 0.
+    #[inline]
+    pub fn iter_values<U: FromBytes + Copy>(&mut self) -> IterValues<'_, 'a, T, U> {
+        assert!(
+            std::mem::size_of::<U>() > 0,
+            "size_of::<U>() must be greater than 0"
+        );
+        IterValues {
+            reader: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, S: AsRef<[u8]>> ByteCordReader<'a, Chain<S>> {
+    /// Returns `length` bytes at its current position and advances the
+    /// position to next aligned offset, or `None` if out of bounds.
+    ///
+    /// Borrows from the underlying segment when the range lies inside one
+    /// of them, or copies into an owned buffer when it straddles a
+    /// segment boundary.
+    #[inline]
+    pub fn next_n(&mut self, length: usize) -> Option<Cow<'a, [u8]>> {
+        if let Some(result) = self.cord.at_n(self.position, length) {
+            self.position = (self.position + length).next_multiple_of(self.alignment);
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an array of size N at its current position and advances the
+    /// position to next aligned offset, or `None` if out of bounds.
+    ///
+    /// Borrows from the underlying segment when the window lies inside
+    /// one of them; only copies when it straddles a segment boundary,
+    /// since a single reference cannot span multiple allocations.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<const N: usize>(&mut self) -> Option<Cow<'a, [u8; N]>> {
+        if let Some(result) = self.cord.at::<N>(self.position) {
+            self.position = (self.position + N).next_multiple_of(self.alignment);
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Advances the position by `length` bytes, aligning the final position.
+    ///
+    /// Returns `true` if the skip was successful (enough bytes remaining).
+    #[inline]
+    pub fn skip(&mut self, length: usize) -> bool {
+        self.next_n(length).is_some()
+    }
+
+    /// Returns count of unread bytes.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.cord.len().saturating_sub(self.position)
+    }
+
+    /// Returns `length` bytes at the current position without advancing,
+    /// or `None` if out of bounds.
+    #[inline]
+    pub fn peek_n(&self, length: usize) -> Option<Cow<'a, [u8]>> {
+        self.cord.at_n(self.position, length)
+    }
+
+    /// Returns an array of size N at the current position without
+    /// advancing, or `None` if out of bounds.
+    #[inline]
+    pub fn peek<const N: usize>(&self) -> Option<Cow<'a, [u8; N]>> {
+        self.cord.at::<N>(self.position)
+    }
+
+    /// Returns a reference to a `U: FromBytes` value at the current
+    /// position and advances the position, or `None` if out of bounds, if
+    /// the window straddles a segment boundary, or if the underlying
+    /// bytes are not aligned to `align_of::<U>()`.
+    ///
+    /// Unlike [`next_copy`](Self::next_copy), this only succeeds when the
+    /// value can be reinterpreted in place without copying; see
+    /// `next_copy` for a copying fallback that works across segment
+    /// boundaries and regardless of alignment.
+    #[inline]
+    pub fn next_ref<U: FromBytes>(&mut self) -> Option<&'a U> {
+        let size = std::mem::size_of::<U>();
+        match self.cord.at_n(self.position, size)? {
+            Cow::Borrowed(slice) => {
+                // See the base reader's `next_ref` for why the real
+                // address, not the logical position, must be checked.
+                if !(slice.as_ptr() as usize).is_multiple_of(std::mem::align_of::<U>()) {
+                    return None;
+                }
+                let ptr = slice.as_ptr();
+                self.position = (self.position + size).next_multiple_of(self.alignment);
+                Some(unsafe { &*(ptr as *const U) })
+            }
+            Cow::Owned(_) => None,
+        }
+    }
+
+    /// Returns a copy of a `U: FromBytes` value at the current position and
+    /// advances the position, or `None` if out of bounds.
+    ///
+    /// Unlike [`next_ref`](Self::next_ref), this works regardless of
+    /// segment boundaries or alignment, at the cost of copying the
+    /// value's bytes.
+    #[inline]
+    pub fn next_copy<U: FromBytes + Copy>(&mut self) -> Option<U> {
+        self.next_n(std::mem::size_of::<U>())
+            .map(|slice| unsafe { std::ptr::read_unaligned(slice.as_ptr() as *const U) })
+    }
+
+    /// Returns a bounded sub-reader over the next `n` bytes that refuses
+    /// reads past that limit, recovering this reader's position advances
+    /// via [`Take::into_inner`].
+    #[inline]
+    pub fn take(&mut self, n: usize) -> Take<'_, 'a, Chain<S>> {
+        Take::new(self, n)
+    }
+
+    /// Returns an iterator over successive `u8`s until this reader is
+    /// exhausted.
+    #[inline]
+    pub fn iter_u8(&mut self) -> IterU8<'_, 'a, Chain<S>> {
+        IterU8 { reader: self }
+    }
+
+    /// Returns an iterator over successive aligned `Cow<'a, [u8]>` windows
+    /// of length `size`.
+    ///
+    /// The final window is yielded even if shorter than `size`; call
+    /// [`Chunks::exact`] on the result to drop it instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[inline]
+    pub fn chunks(&mut self, size: usize) -> Chunks<'_, 'a, Chain<S>> {
+        assert!(size > 0, "chunk size must be greater than 0");
+        Chunks {
+            reader: self,
+            size,
+            exact: false,
+        }
+    }
+
+    /// Returns an iterator over successive decoded `U: FromBytes` records
+    /// until fewer than `size_of::<U>()` bytes remain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size_of::<U>()` is 0.
+    #[inline]
+    pub fn iter_values<U: FromBytes + Copy>(&mut self) -> IterValues<'_, 'a, Chain<S>, U> {
+        assert!(
+            std::mem::size_of::<U>() > 0,
+            "size_of::<U>() must be greater than 0"
+        );
+        IterValues {
+            reader: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Copies up to `buf.len()` unread bytes into `buf` and returns the
+/// number of bytes copied (`0` once the reader is exhausted).
+///
+/// Unlike [`next_n`](Self::next_n), this advances the position by
+/// exactly the number of bytes copied, without rounding up to the next
+/// aligned offset: a real `Read` consumer (serde_json, flate2, a hasher)
+/// picks buffer sizes unrelated to the cord's alignment, and skipping
+/// the bytes between a partial read and the next aligned offset would
+/// silently drop them. Use [`next_n`](Self::next_n) directly if
+/// alignment-rounded reads are what you want.
+#[cfg(feature = "std")]
+impl<T: AsRef<[u8]>> std::io::Read for ByteCordReader<'_, T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.remaining().min(buf.len());
+        if available == 0 {
+            return Ok(0);
+        }
+        let slice = self
+            .cord
+            .at_n(self.position, available)
+            .expect("`available` was derived from `remaining()`, so this is in bounds");
+        buf[..available].copy_from_slice(slice);
+        self.position += available;
+        Ok(available)
+    }
 }
 
 impl<T: AsRef<[u8]>> ByteCordReader<'_, T> {
@@ -204,3 +501,127 @@ impl<T: AsRef<[u8]>> ByteCordReader<'_, T> {
         self.next::<16>().map(|arr| i128::from_le_bytes(*arr))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_is_byte_exact_across_split_calls() {
+        use std::io::Read;
+
+        // alignment 4 would round `next_n`'s position up to the next
+        // multiple of 4 after each read; `read` must advance by exactly
+        // the number of bytes copied instead, or a partial read here would
+        // silently drop the bytes between it and the next aligned offset.
+        let data: Vec<u8> = (0..10u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = ByteCordReader::with_alignment(&cord, 4);
+
+        let mut buf = [0u8; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [0, 1, 2]);
+
+        let mut buf = [0u8; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf, [3, 4, 5]);
+
+        let mut buf = [0u8; 10];
+        assert_eq!(reader.read(&mut buf).unwrap(), 4);
+        assert_eq!(&buf[..4], &[6, 7, 8, 9]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_returns_zero_once_exhausted() {
+        use std::io::Read;
+
+        let cord = ByteCord::new(vec![1u8, 2]);
+        let mut reader = ByteCordReader::new(&cord);
+
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn next_n_rounds_position_up_to_alignment() {
+        let data: Vec<u8> = (0..10u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = ByteCordReader::with_alignment(&cord, 4);
+
+        assert_eq!(reader.next_n(3), Some(&[0u8, 1, 2][..]));
+        // position rounded 3 -> 4, so the next read starts at 4, not 3.
+        assert_eq!(reader.next_n(2), Some(&[4u8, 5][..]));
+    }
+
+    #[test]
+    fn chain_next_n_borrows_in_segment_and_copies_across_segments() {
+        let cord = ByteCord::chain(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        let mut reader = cord.read();
+
+        assert!(matches!(reader.next_n(2), Some(Cow::Borrowed(_))));
+        assert!(matches!(reader.next_n(2), Some(Cow::Owned(_))));
+    }
+
+    #[test]
+    fn chain_next_ref_only_succeeds_within_a_single_segment() {
+        let cord = ByteCord::chain(vec![1u8, 2, 3, 4], vec![5u8, 6, 7, 8]);
+        let mut reader = cord.read();
+
+        // entirely within the first segment: succeeds.
+        assert_eq!(reader.next_ref::<u8>(), Some(&1u8));
+        // straddles the two segments at position 3..4+1: refuses to copy.
+        let mut straddling = cord.read();
+        straddling.skip(3);
+        assert_eq!(straddling.next_ref::<[u8; 2]>(), None);
+    }
+
+    #[test]
+    fn chain_next_copy_works_across_segment_boundaries() {
+        let cord = ByteCord::chain(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        let mut reader = cord.read();
+        reader.skip(2);
+        assert_eq!(reader.next_copy::<u16>(), Some(u16::from_ne_bytes([3, 4])));
+    }
+
+    #[test]
+    fn chain_peek_does_not_advance_position() {
+        let cord = ByteCord::chain(vec![1u8, 2], vec![3u8, 4]);
+        let reader = cord.read();
+        assert_eq!(reader.peek_n(2).unwrap().into_owned(), vec![1u8, 2]);
+        assert_eq!(reader.peek_n(2).unwrap().into_owned(), vec![1u8, 2]);
+    }
+
+    #[test]
+    fn chain_take_refuses_reads_past_its_limit() {
+        let cord = ByteCord::chain(vec![1u8, 2, 3], vec![4u8, 5, 6]);
+        let mut reader = cord.read();
+        let mut take = reader.take(3);
+        assert_eq!(take.next_n(2).unwrap().into_owned(), vec![1u8, 2]);
+        assert_eq!(take.next_n(2), None);
+    }
+
+    #[test]
+    fn chain_iter_u8_yields_every_byte_across_segments() {
+        let cord = ByteCord::chain(vec![1u8, 2], vec![3u8, 4]);
+        let mut reader = cord.read();
+        assert_eq!(reader.iter_u8().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn chain_chunks_yields_cow_windows_across_segments() {
+        let cord = ByteCord::chain(vec![1u8, 2], vec![3u8, 4, 5]);
+        let mut reader = cord.read();
+        let chunks: Vec<_> = reader.chunks(2).map(Cow::into_owned).collect();
+        assert_eq!(chunks, vec![vec![1u8, 2], vec![3u8, 4], vec![5u8]]);
+    }
+
+    #[test]
+    fn chain_iter_values_decodes_records_across_segments() {
+        let cord = ByteCord::chain(vec![1u8, 0], vec![2u8, 0]);
+        let mut reader = cord.read();
+        assert_eq!(reader.iter_values::<u16>().collect::<Vec<_>>(), vec![1u16, 2]);
+    }
+}
@@ -0,0 +1,195 @@
+//! This module provides [`Take`].
+
+use std::borrow::Cow;
+
+use crate::{ByteCordReader, Chain};
+
+/// A bounded view over a [`ByteCordReader`] returned by
+/// [`ByteCordReader::take`].
+///
+/// Reports [`remaining`](Take::remaining) capped at the configured limit
+/// and refuses reads past it, which makes it useful for length-prefixed
+/// framing: read a length, then `take` a bounded region for the payload
+/// parser so it cannot over-read into the next frame.
+pub struct Take<'r, 'a, T> {
+    reader: &'r mut ByteCordReader<'a, T>,
+    start: usize,
+    limit: usize,
+}
+
+impl<'r, 'a, T> Take<'r, 'a, T> {
+    #[inline]
+    pub(crate) fn new(reader: &'r mut ByteCordReader<'a, T>, limit: usize) -> Self {
+        let start = reader.position();
+        Take {
+            reader,
+            start,
+            limit,
+        }
+    }
+
+    /// Recovers the inner reader, at whatever position was reached while
+    /// reading through this [`Take`].
+    #[inline]
+    pub fn into_inner(self) -> &'r mut ByteCordReader<'a, T> {
+        self.reader
+    }
+
+    /// Returns how many bytes the underlying reader has actually advanced
+    /// by since this [`Take`] was created, capped at `limit`.
+    ///
+    /// This is tracked against the reader's real position rather than the
+    /// sum of requested lengths, since the reader rounds its position up
+    /// to the next aligned offset after each read: counting requested
+    /// lengths alone would under-count the alignment padding and let a
+    /// read past `limit` slip through into the next frame. Capping at
+    /// `limit` is needed for the same reason in reverse — a read that
+    /// lands just inside `limit` can still round the reader's position
+    /// past `start + limit`, so the raw position delta can overshoot
+    /// `limit` too, which would otherwise underflow the subtraction in
+    /// [`remaining`](Self::remaining).
+    #[inline]
+    fn consumed(&self) -> usize {
+        (self.reader.position() - self.start).min(self.limit)
+    }
+}
+
+impl<'r, 'a, T: AsRef<[u8]>> Take<'r, 'a, T> {
+    /// Returns count of bytes that may still be read through this
+    /// [`Take`], i.e. the inner reader's `remaining()` capped at this
+    /// limit.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        (self.limit - self.consumed()).min(self.reader.remaining())
+    }
+
+    /// Returns `length` bytes at the current position and advances the
+    /// position, or `None` if out of bounds or past this limit.
+    #[inline]
+    pub fn next_n(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.consumed() + length > self.limit {
+            return None;
+        }
+        self.reader.next_n(length)
+    }
+
+    /// Returns an array of size S at the current position and advances the
+    /// position, or `None` if out of bounds or past this limit.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<const S: usize>(&mut self) -> Option<&'a [u8; S]> {
+        if self.consumed() + S > self.limit {
+            return None;
+        }
+        self.reader.next::<S>()
+    }
+
+    /// Advances the position by `length` bytes, aligning the final
+    /// position.
+    ///
+    /// Returns `true` if the skip was successful (enough bytes remaining
+    /// within this limit).
+    #[inline]
+    pub fn skip(&mut self, length: usize) -> bool {
+        self.next_n(length).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ByteCord;
+
+    #[test]
+    fn remaining_tracks_alignment_rounded_reads() {
+        let data: Vec<u8> = (0..20u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = cord.read_with_alignment(4);
+
+        let mut take = reader.take(5);
+        assert_eq!(take.remaining(), 5);
+        // rounds the reader's position 3 -> 4, under-counting by 1 would
+        // let a later read slip one byte past the limit.
+        take.next_n(3);
+        assert_eq!(take.remaining(), 1);
+    }
+
+    #[test]
+    fn remaining_clamps_when_a_read_rounds_past_the_limit() {
+        let data: Vec<u8> = (0..20u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = cord.read_with_alignment(8);
+
+        // a take(5) whose only read (4 bytes) rounds the reader's position
+        // up to 8 overshoots `start + limit` by 3; `remaining` must clamp
+        // instead of underflowing the subtraction.
+        let mut take = reader.take(5);
+        take.next_n(4);
+        assert_eq!(take.remaining(), 0);
+    }
+
+    #[test]
+    fn next_n_refuses_reads_past_the_limit() {
+        let data: Vec<u8> = (0..20u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = cord.read_with_alignment(1);
+
+        let mut take = reader.take(4);
+        assert_eq!(take.next_n(3), Some(&[0u8, 1, 2][..]));
+        assert_eq!(take.next_n(2), None);
+    }
+
+    #[test]
+    fn into_inner_recovers_the_reader_at_its_reached_position() {
+        let data: Vec<u8> = (0..20u8).collect();
+        let cord = ByteCord::new(data);
+        let mut reader = cord.read_with_alignment(1);
+
+        {
+            let mut take = reader.take(4);
+            take.next_n(4);
+            take.into_inner();
+        }
+        assert_eq!(reader.next_n(2), Some(&[4u8, 5][..]));
+    }
+}
+
+impl<'r, 'a, S: AsRef<[u8]>> Take<'r, 'a, Chain<S>> {
+    /// Returns count of bytes that may still be read through this
+    /// [`Take`], i.e. the inner reader's `remaining()` capped at this
+    /// limit.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        (self.limit - self.consumed()).min(self.reader.remaining())
+    }
+
+    /// Returns `length` bytes at the current position and advances the
+    /// position, or `None` if out of bounds or past this limit.
+    #[inline]
+    pub fn next_n(&mut self, length: usize) -> Option<Cow<'a, [u8]>> {
+        if self.consumed() + length > self.limit {
+            return None;
+        }
+        self.reader.next_n(length)
+    }
+
+    /// Returns an array of size N at the current position and advances the
+    /// position, or `None` if out of bounds or past this limit.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<const N: usize>(&mut self) -> Option<Cow<'a, [u8; N]>> {
+        if self.consumed() + N > self.limit {
+            return None;
+        }
+        self.reader.next::<N>()
+    }
+
+    /// Advances the position by `length` bytes, aligning the final
+    /// position.
+    ///
+    /// Returns `true` if the skip was successful (enough bytes remaining
+    /// within this limit).
+    #[inline]
+    pub fn skip(&mut self, length: usize) -> bool {
+        self.next_n(length).is_some()
+    }
+}